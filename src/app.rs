@@ -1,33 +1,50 @@
+use crate::components::theme::ThemeProvider;
+use crate::error_template::ErrorTemplate;
 use leptos::*;
 
-// Demonstrate Client Code Can't Run on Server error
 #[component]
 pub fn App(cx: Scope) -> impl IntoView {
-    use gloo_storage::Storage;
-    // panicked at 'cannot call wasm-bindgen imported functions on non-wasm targets'
-    // let storage = gloo_storage::LocalStorage::raw();
-    // leptos::log!("{storage:?}");
-
-    // Solution wrap `storage...` in create_effect
-    create_effect(cx, move |_| {
-        let storage = gloo_storage::LocalStorage::raw();
-        leptos::log!("{storage:?}");
-    });
-
     view! { cx,
       <h1>"Hello, World, it works!"</h1>
+      <ThemeProvider>
+        <ErrorBoundary fallback=|cx, errors| view! { cx, <ErrorTemplate errors=errors/> }>
+          <HomePage/>
+        </ErrorBoundary>
+      </ThemeProvider>
     }
 }
 
 /// Renders the home page of your application.
 #[component]
 fn HomePage(cx: Scope) -> impl IntoView {
-    // Creates a reactive value to update the button
-    let (count, set_count) = create_signal(cx, 0);
-    let on_click = move |_| set_count.update(|count| *count += 1);
+    use crate::components::button::{Button, Variant};
+    use crate::components::theme::ThemeToggle;
+    use crate::island::island_marker;
+    use crate::todo::{AddTodosButton, BusyButton, FileUpload, TodoList};
 
     view! { cx,
         <h1>"Welcome to Leptos!"</h1>
+        {island_marker(cx, "Counter", "0", |cx| view! { cx, <Counter initial=0/> })}
+        {island_marker(cx, "BusyButton", "", |cx| view! { cx, <BusyButton/> })}
+        {island_marker(cx, "AddTodosButton", "", |cx| view! { cx, <AddTodosButton/> })}
+        {island_marker(cx, "FileUpload", "", |cx| view! { cx, <FileUpload/> })}
+        {island_marker(cx, "ThemeToggle", "", |cx| view! { cx, <ThemeToggle/> })}
+        <Button variant=Variant::PRIMARY/>
+        <TodoList/>
+    }
+}
+
+/// The page's one piece of interactive state. Shipped to the client as
+/// its own island (see `island.rs`), and persisted across reloads and
+/// tabs via `use_local_storage` instead of a plain signal.
+#[component]
+pub fn Counter(cx: Scope, #[prop(default = 0)] initial: i32) -> impl IntoView {
+    use crate::local_storage::use_local_storage;
+
+    let (count, set_count) = use_local_storage(cx, "leptos_tutorial_count", initial);
+    let on_click = move |_| set_count.update(|count| *count += 1);
+
+    view! { cx,
         <button on:click=on_click>"Click Me: " {count}</button>
     }
 }
@@ -23,8 +23,8 @@ struct ButtonColors {
     border: String,
 }
 
-fn get_colors(variant: &Variant) -> ButtonColors {
-    let theme = theme::get_theme().unwrap();
+fn get_colors(cx: Scope, variant: &Variant) -> ButtonColors {
+    let theme = theme::use_theme(cx).get();
     match variant {
         Variant::PRIMARY => ButtonColors {
             text: theme.white(),
@@ -53,7 +53,7 @@ fn get_colors(variant: &Variant) -> ButtonColors {
 pub fn Button(cx: Scope, variant: Variant) -> impl IntoView {
     let disabled = variant.is(&Variant::DISABLED);
 
-    let styles = styles(&variant);
+    let styles = styles(cx, &variant);
 
     styled::view! {
         cx,
@@ -63,8 +63,8 @@ pub fn Button(cx: Scope, variant: Variant) -> impl IntoView {
 }
 
 #[allow(non_upper_case_globals)]
-fn styles<'a>(variant: &Variant) -> stylist::Result<Styles> {
-    let colors = get_colors(variant);
+fn styles<'a>(cx: Scope, variant: &Variant) -> stylist::Result<Styles> {
+    let colors = get_colors(cx, variant);
 
     style!(
             button {
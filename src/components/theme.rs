@@ -1,6 +1,21 @@
+use crate::local_storage::use_local_storage;
 use csscolorparser::Color;
+use leptos::*;
+use serde::{Deserialize, Serialize};
+
+/// Which palette `get_theme` should build.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ColorMode {
+    Light,
+    Dark,
+}
+
+pub fn get_theme(mode: ColorMode) -> Result<Theme, csscolorparser::ParseColorError> {
+    let (black, white) = match mode {
+        ColorMode::Light => ("#000000", "#FFFFFF"),
+        ColorMode::Dark => ("#FFFFFF", "#1A1A1A"),
+    };
 
-pub fn get_theme() -> Result<Theme, csscolorparser::ParseColorError> {
     let theme = Theme {
         teal: Colors {
             main: Color::from_html("#6FDDDB")?,
@@ -39,8 +54,8 @@ pub fn get_theme() -> Result<Theme, csscolorparser::ParseColorError> {
             lightest: Color::from_html("#c4c4c4")?,
         },
         red: Color::from_html("#FF5854")?,
-        black: Color::from_html("#000000")?,
-        white: Color::from_html("#FFFFFF")?,
+        black: Color::from_html(black)?,
+        white: Color::from_html(white)?,
         transparent: Color::from_html("transparent")?,
     };
 
@@ -105,3 +120,135 @@ impl Theme {
         self.transparent.to_hex_string()
     }
 }
+
+/// Puts the active `Theme` into context as a [`Signal`] derived from
+/// [`use_color_mode`], with the mode and its setter alongside it.
+/// `Button` reads the palette once, when it is built, so a `Button`
+/// already rendered keeps its colors when the mode changes; nothing in
+/// this crate reads the mode or setter from this context.
+#[component]
+pub fn ThemeProvider(cx: Scope, children: Children) -> impl IntoView {
+    let (mode, set_mode) = use_color_mode(cx, None);
+    let theme = Signal::derive(cx, move || {
+        get_theme(mode.get()).expect("theme color strings are valid CSS colors")
+    });
+
+    provide_context(cx, theme);
+    provide_context(cx, mode);
+    provide_context(cx, set_mode);
+
+    children(cx)
+}
+
+/// A button that flips the active `ColorMode`. Mounted as its own
+/// island (see `island.rs`), so it resolves the mode itself via
+/// `use_color_mode` rather than reading `ThemeProvider`'s context —
+/// that context lives in the full-page SSR scope, which an
+/// independently-hydrated island doesn't share. The toggle updates its
+/// own label and the stored mode; `Button`s are rendered on the server,
+/// where `use_color_mode` has no `localStorage` and resolves to
+/// `ColorMode::Light`, so their colors do not follow the toggle.
+#[component]
+pub fn ThemeToggle(cx: Scope) -> impl IntoView {
+    let (mode, set_mode) = use_color_mode(cx, None);
+
+    let toggle = move |_| {
+        set_mode.set(match mode.get() {
+            ColorMode::Light => ColorMode::Dark,
+            ColorMode::Dark => ColorMode::Light,
+        });
+    };
+
+    view! { cx,
+        <button on:click=toggle>
+            {move || match mode.get() {
+                ColorMode::Light => "Switch to dark mode",
+                ColorMode::Dark => "Switch to light mode",
+            }}
+        </button>
+    }
+}
+
+/// Reads the `Signal<Theme>` [`ThemeProvider`] put into context,
+/// falling back to the light palette if none is present (e.g. a
+/// `Button` rendered outside a `ThemeProvider`).
+pub fn use_theme(cx: Scope) -> Signal<Theme> {
+    match use_context::<Signal<Theme>>(cx) {
+        Some(theme) => theme,
+        None => {
+            let theme = store_value(
+                cx,
+                get_theme(ColorMode::Light).expect("theme color strings are valid CSS colors"),
+            );
+            Signal::derive(cx, move || theme.get_value())
+        }
+    }
+}
+
+/// Resolves the active `ColorMode`, in priority order, from: `explicit`
+/// if given, otherwise whatever mode was last chosen via the setter
+/// this returns (persisted in `localStorage`), otherwise the OS's
+/// `prefers-color-scheme` — tracked live, so toggling the OS setting
+/// updates the signal even if nothing has been explicitly chosen yet.
+pub fn use_color_mode(
+    cx: Scope,
+    explicit: Option<ReadSignal<ColorMode>>,
+) -> (Signal<ColorMode>, SignalSetter<ColorMode>) {
+    let system = prefers_dark_color_scheme(cx);
+    let (chosen, set_chosen) = use_local_storage::<Option<ColorMode>>(
+        cx,
+        "leptos_tutorial_color_mode",
+        None,
+    );
+
+    let mode = Signal::derive(cx, move || {
+        explicit
+            .map(|mode| mode.get())
+            .or_else(|| chosen.get())
+            .unwrap_or_else(|| system.get())
+    });
+    let set_mode = SignalSetter::map(cx, move |new_mode| set_chosen.set(Some(new_mode)));
+
+    (mode, set_mode)
+}
+
+#[cfg(feature = "ssr")]
+fn prefers_dark_color_scheme(cx: Scope) -> ReadSignal<ColorMode> {
+    create_signal(cx, ColorMode::Light).0
+}
+
+#[cfg(not(feature = "ssr"))]
+fn prefers_dark_color_scheme(cx: Scope) -> ReadSignal<ColorMode> {
+    use wasm_bindgen::{prelude::Closure, JsCast};
+
+    let query = leptos::window()
+        .match_media("(prefers-color-scheme: dark)")
+        .ok()
+        .flatten();
+
+    let initial = query.as_ref().map(|q| q.matches()).unwrap_or(false);
+    let (mode, set_mode) = create_signal(
+        cx,
+        if initial {
+            ColorMode::Dark
+        } else {
+            ColorMode::Light
+        },
+    );
+
+    if let Some(query) = query {
+        let on_change = Closure::<dyn Fn(web_sys::MediaQueryListEvent)>::wrap(Box::new(
+            move |ev: web_sys::MediaQueryListEvent| {
+                set_mode.set(if ev.matches() {
+                    ColorMode::Dark
+                } else {
+                    ColorMode::Light
+                });
+            },
+        ));
+        _ = query.add_event_listener_with_callback("change", on_change.as_ref().unchecked_ref());
+        on_change.forget();
+    }
+
+    mode
+}
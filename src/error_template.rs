@@ -0,0 +1,95 @@
+// error_template.rs
+
+use leptos::*;
+use miette::{Diagnostic, Severity};
+use thiserror::Error;
+
+#[derive(Error, Diagnostic, Debug, Clone)]
+pub enum AppError {
+    #[error("Not Found")]
+    #[diagnostic(code(app::not_found), help("Check the URL and try again."))]
+    NotFound,
+
+    #[error("{0}")]
+    #[diagnostic(
+        code(app::server_error),
+        help("Something went wrong handling your request; please try again.")
+    )]
+    ServerError(String),
+}
+
+impl AppError {
+    pub fn status_code(&self) -> http::StatusCode {
+        match self {
+            AppError::NotFound => http::StatusCode::NOT_FOUND,
+            AppError::ServerError(_) => http::StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl From<ServerFnError> for AppError {
+    fn from(error: ServerFnError) -> Self {
+        AppError::ServerError(error.to_string())
+    }
+}
+
+/// The `fallback` for `<ErrorBoundary>`: renders each `AppError`'s
+/// diagnostic code, message and `help` text, and — on the server —
+/// sets the response status to the most severe error's status code.
+/// Errors of any other type are not rendered.
+#[component]
+pub fn ErrorTemplate(
+    cx: Scope,
+    #[prop(optional)] outside_errors: Option<Errors>,
+    #[prop(optional)] errors: Option<RwSignal<Errors>>,
+) -> impl IntoView {
+    let errors = match outside_errors {
+        Some(e) => create_rw_signal(cx, e),
+        None => errors.expect("No Errors found and we expected errors!"),
+    };
+
+    let errors: Vec<AppError> = errors
+        .get()
+        .into_iter()
+        .filter_map(|(_k, v)| v.downcast_ref::<AppError>().cloned())
+        .collect();
+
+    #[cfg(feature = "ssr")]
+    {
+        use leptos_axum::ResponseOptions;
+
+        if let Some(response) = use_context::<ResponseOptions>(cx) {
+            let worst = errors
+                .iter()
+                .max_by_key(|e| e.status_code().as_u16())
+                .map(AppError::status_code)
+                .unwrap_or(http::StatusCode::INTERNAL_SERVER_ERROR);
+            response.set_status(worst);
+        }
+    }
+
+    view! { cx,
+        <h1>{if errors.len() > 1 { "Errors" } else { "Error" }}</h1>
+        <For
+            each=move || errors.clone().into_iter().enumerate()
+            key=|(index, _error)| *index
+            view=move |cx, (_, error)| {
+                let code = error.code().map(|c| c.to_string()).unwrap_or_default();
+                let severity = match error.severity().unwrap_or(Severity::Error) {
+                    Severity::Advice => "advice",
+                    Severity::Warning => "warning",
+                    Severity::Error => "error",
+                };
+                let help = error.help().map(|h| h.to_string());
+
+                view! { cx,
+                    <div class=format!("diagnostic diagnostic-{severity}")>
+                        <p class="diagnostic-code">{code}</p>
+                        <p class="diagnostic-message">{error.to_string()}</p>
+                        {help.map(|help| view! { cx, <p class="diagnostic-help">{help}</p> })}
+                    </div>
+                }
+            }
+        />
+    }
+}
@@ -0,0 +1,82 @@
+// island.rs
+
+use leptos::*;
+
+/// Wraps the view produced by `view_fn` in a marker element that
+/// [`hydrate_islands`] can find and hydrate on the client. `props` is
+/// whatever the island needs to rebuild its initial state, serialized
+/// to a plain string (JSON for anything more than a scalar).
+pub fn island_marker<V: IntoView>(
+    cx: Scope,
+    name: &'static str,
+    props: &str,
+    view_fn: impl FnOnce(Scope) -> V,
+) -> impl IntoView {
+    let hydration_key = leptos::leptos_dom::HydrationCtx::peek().to_string();
+    let view = view_fn(cx);
+
+    view! {
+        cx,
+        <leptos-island data-island=name data-props=props.to_string() data-hk=hydration_key>
+            {view}
+        </leptos-island>
+    }
+}
+
+/// Hydrates every island marker found in the document in place,
+/// instead of hydrating the whole `<App/>` tree. Call this from
+/// `hydrate()`.
+#[cfg(feature = "hydrate")]
+pub fn hydrate_islands() {
+    use crate::app::Counter;
+    use crate::components::theme::ThemeToggle;
+    use crate::todo::{AddTodosButton, BusyButton, FileUpload};
+    use leptos::leptos_dom::HydrationCtx;
+    use wasm_bindgen::JsCast;
+
+    let document = leptos::document();
+    let islands = document
+        .query_selector_all("leptos-island")
+        .expect("query_selector_all should not fail on a valid selector");
+
+    for i in 0..islands.length() {
+        let el = islands.get(i).unwrap().unchecked_into::<web_sys::Element>();
+        let props = el.get_attribute("data-props").unwrap_or_default();
+        let hydration_key = el.get_attribute("data-hk").unwrap_or_default();
+
+        // Replay the id sequence the full SSR pass assigned to this
+        // subtree, so the island's own ids line up with the ones
+        // already in the DOM instead of starting over from zero.
+        HydrationCtx::continue_from(hydration_key.into());
+
+        match el.get_attribute("data-island").as_deref() {
+            Some("Counter") => {
+                let initial = props.parse().unwrap_or(0);
+                leptos::mount_to(el.unchecked_into(), move |cx| {
+                    view! { cx, <Counter initial=initial/> }
+                });
+            }
+            Some("BusyButton") => {
+                leptos::mount_to(el.unchecked_into(), move |cx| {
+                    view! { cx, <BusyButton/> }
+                });
+            }
+            Some("AddTodosButton") => {
+                leptos::mount_to(el.unchecked_into(), move |cx| {
+                    view! { cx, <AddTodosButton/> }
+                });
+            }
+            Some("FileUpload") => {
+                leptos::mount_to(el.unchecked_into(), move |cx| {
+                    view! { cx, <FileUpload/> }
+                });
+            }
+            Some("ThemeToggle") => {
+                leptos::mount_to(el.unchecked_into(), move |cx| {
+                    view! { cx, <ThemeToggle/> }
+                });
+            }
+            other => leptos::warn!("no island registered for {other:?}"),
+        }
+    }
+}
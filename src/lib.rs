@@ -1,7 +1,11 @@
 use cfg_if::cfg_if;
 pub mod app;
+pub mod components;
 pub mod error_template;
 pub mod fileserve;
+pub mod island;
+pub mod local_storage;
+pub mod store;
 pub mod todo;
 /*
 Seems odd that `mod todo` must be defined here, in lib.rs
@@ -23,9 +27,7 @@ See: https://doc.rust-lang.org/stable/reference/items/modules.html#module-source
     */
 
 cfg_if! { if #[cfg(feature = "hydrate")] {
-    use leptos::*;
     use wasm_bindgen::prelude::wasm_bindgen;
-    use crate::app::*;
 
     #[wasm_bindgen]
     pub fn hydrate() {
@@ -33,8 +35,8 @@ cfg_if! { if #[cfg(feature = "hydrate")] {
         _ = console_log::init_with_level(log::Level::Info);
         console_error_panic_hook::set_once();
 
-        leptos::mount_to_body(move |cx| {
-            view! { cx, <App/> }
-        });
+        // Only the `<leptos-island>` markers get hydrated, not the
+        // whole `<App/>` tree — see `island.rs`.
+        crate::island::hydrate_islands();
     }
 }}
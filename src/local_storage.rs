@@ -0,0 +1,54 @@
+// local_storage.rs
+
+use leptos::*;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// A `(ReadSignal<T>, WriteSignal<T>)` pair backed by
+/// `localStorage[key]`, kept in sync across browser tabs.
+pub fn use_local_storage<T>(
+    cx: Scope,
+    key: &'static str,
+    default: T,
+) -> (ReadSignal<T>, WriteSignal<T>)
+where
+    T: Serialize + DeserializeOwned + Clone + PartialEq + 'static,
+{
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "ssr")] {
+            create_signal(cx, default)
+        } else {
+            use gloo_storage::{LocalStorage, Storage};
+            use wasm_bindgen::{prelude::Closure, JsCast};
+
+            let initial = LocalStorage::get::<T>(key).unwrap_or(default);
+            let (value, set_value) = create_signal(cx, initial);
+
+            // Write back whenever the signal changes.
+            create_effect(cx, move |_| {
+                if let Err(e) = LocalStorage::set(key, value.get()) {
+                    leptos::error!("failed to write {key} to localStorage: {e}");
+                }
+            });
+
+            // Stay in sync with changes made in other tabs.
+            let on_storage = Closure::<dyn Fn(web_sys::StorageEvent)>::wrap(Box::new(
+                move |ev: web_sys::StorageEvent| {
+                    if ev.key().as_deref() != Some(key) {
+                        return;
+                    }
+                    if let Some(new_value) = ev
+                        .new_value()
+                        .and_then(|raw| serde_json::from_str::<T>(&raw).ok())
+                    {
+                        set_value.set(new_value);
+                    }
+                },
+            ));
+            _ = leptos::window()
+                .add_event_listener_with_callback("storage", on_storage.as_ref().unchecked_ref());
+            on_storage.forget();
+
+            (value, set_value)
+        }
+    }
+}
@@ -96,7 +96,12 @@ async fn main() {
     use axum::{routing::post, Router};
     use leptos::*;
     use leptos_axum::{generate_route_list, LeptosRoutes};
-    use leptos_tutorial::{app::*, fileserve::file_and_error_handler};
+    use leptos_tutorial::{
+        app::*,
+        fileserve::file_and_error_handler,
+        store::{DefaultTodoStore, TodoStore},
+    };
+    use std::sync::Arc;
 
     leptos::log!("Running with feature = 'ssr'");
     simple_logger::init_with_level(log::Level::Info)
@@ -112,9 +117,29 @@ async fn main() {
     let addr = leptos_options.site_addr;
     let routes = generate_route_list(|cx| view! { cx, <App/> }).await;
 
-    // build our application with a route
+    // Build whichever backend the `surrealdb` feature selects once,
+    // here, and hand it to every server function through Leptos
+    // context instead of each one reaching for a global connection.
+    let store: Arc<dyn TodoStore> = cfg_if::cfg_if! {
+        if #[cfg(feature = "surrealdb")] {
+            let db = surrealdb::engine::any::connect("mem://").await.unwrap();
+            Arc::new(DefaultTodoStore::new(db))
+        } else {
+            let pool = sqlx::SqlitePool::connect("sqlite:Todos.db").await.unwrap();
+            Arc::new(DefaultTodoStore::new(pool))
+        }
+    };
+
+    // `leptos_routes` puts a `ResponseOptions` in context for every
+    // request; `error_template::ErrorTemplate` uses it to set the SSR
+    // response's HTTP status to match a propagated `AppError`.
     let app = Router::new()
-        .route("/api/*fn_name", post(leptos_axum::handle_server_fns))
+        .route(
+            "/api/*fn_name",
+            post(leptos_axum::handle_server_fns_with_context(move |cx| {
+                provide_context(cx, store.clone());
+            })),
+        )
         .leptos_routes(&leptos_options, routes, |cx| view! { cx, <App/> })
         .fallback(file_and_error_handler)
         .with_state(leptos_options);
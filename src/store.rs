@@ -0,0 +1,216 @@
+// store.rs
+
+#![cfg(feature = "ssr")]
+
+use crate::todo::Todo;
+use async_trait::async_trait;
+use leptos::ServerFnError;
+
+#[async_trait]
+pub trait TodoStore: Send + Sync {
+    async fn insert(&self, title: String) -> Result<(), ServerFnError>;
+    async fn insert_with_attachment(
+        &self,
+        title: String,
+        attachment: Vec<u8>,
+    ) -> Result<(), ServerFnError>;
+    async fn insert_many(&self, items: Vec<Todo>) -> Result<(), ServerFnError>;
+    async fn list(&self) -> Result<Vec<Todo>, ServerFnError>;
+    async fn toggle(&self, id: i64) -> Result<(), ServerFnError>;
+    async fn delete(&self, id: i64) -> Result<(), ServerFnError>;
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "surrealdb")] {
+        pub use surreal::SurrealTodoStore as DefaultTodoStore;
+    } else {
+        pub use sqlite::SqliteTodoStore as DefaultTodoStore;
+    }
+}
+
+pub mod sqlite {
+    use super::TodoStore;
+    use crate::todo::Todo;
+    use async_trait::async_trait;
+    use leptos::ServerFnError;
+    use sqlx::SqlitePool;
+
+    /// The tutorial's original backend, now behind `TodoStore`.
+    pub struct SqliteTodoStore {
+        pool: SqlitePool,
+    }
+
+    impl SqliteTodoStore {
+        pub fn new(pool: SqlitePool) -> Self {
+            Self { pool }
+        }
+    }
+
+    #[async_trait]
+    impl TodoStore for SqliteTodoStore {
+        async fn insert(&self, title: String) -> Result<(), ServerFnError> {
+            sqlx::query("INSERT INTO todos (title, completed) VALUES ($1, false)")
+                .bind(title)
+                .execute(&self.pool)
+                .await
+                .map(|_row| ())
+                .map_err(|e| ServerFnError::ServerError(e.to_string()))
+        }
+
+        async fn list(&self) -> Result<Vec<Todo>, ServerFnError> {
+            sqlx::query_as::<_, (i64, String, bool)>("SELECT id, title, completed FROM todos")
+                .fetch_all(&self.pool)
+                .await
+                .map(|rows| {
+                    rows.into_iter()
+                        .map(|(id, title, completed)| Todo { id, title, completed })
+                        .collect()
+                })
+                .map_err(|e| ServerFnError::ServerError(e.to_string()))
+        }
+
+        async fn insert_with_attachment(
+            &self,
+            title: String,
+            attachment: Vec<u8>,
+        ) -> Result<(), ServerFnError> {
+            sqlx::query(
+                "INSERT INTO todos (title, completed, attachment) VALUES ($1, false, $2)",
+            )
+            .bind(title)
+            .bind(attachment)
+            .execute(&self.pool)
+            .await
+            .map(|_row| ())
+            .map_err(|e| ServerFnError::ServerError(e.to_string()))
+        }
+
+        async fn insert_many(&self, items: Vec<Todo>) -> Result<(), ServerFnError> {
+            for todo in items {
+                sqlx::query("INSERT INTO todos (title, completed) VALUES ($1, $2)")
+                    .bind(todo.title)
+                    .bind(todo.completed)
+                    .execute(&self.pool)
+                    .await
+                    .map_err(|e| ServerFnError::ServerError(e.to_string()))?;
+            }
+            Ok(())
+        }
+
+        async fn toggle(&self, id: i64) -> Result<(), ServerFnError> {
+            sqlx::query("UPDATE todos SET completed = NOT completed WHERE id = $1")
+                .bind(id)
+                .execute(&self.pool)
+                .await
+                .map(|_row| ())
+                .map_err(|e| ServerFnError::ServerError(e.to_string()))
+        }
+
+        async fn delete(&self, id: i64) -> Result<(), ServerFnError> {
+            sqlx::query("DELETE FROM todos WHERE id = $1")
+                .bind(id)
+                .execute(&self.pool)
+                .await
+                .map(|_row| ())
+                .map_err(|e| ServerFnError::ServerError(e.to_string()))
+        }
+    }
+}
+
+#[cfg(feature = "surrealdb")]
+pub mod surreal {
+    use super::TodoStore;
+    use crate::todo::Todo;
+    use async_trait::async_trait;
+    use leptos::ServerFnError;
+    use surrealdb::engine::any::Any;
+    use surrealdb::Surreal;
+
+    /// Same `TodoStore` contract, backed by SurrealDB instead of
+    /// sqlite. Selected with `--features surrealdb`.
+    pub struct SurrealTodoStore {
+        db: Surreal<Any>,
+    }
+
+    impl SurrealTodoStore {
+        pub fn new(db: Surreal<Any>) -> Self {
+            Self { db }
+        }
+    }
+
+    #[async_trait]
+    impl TodoStore for SurrealTodoStore {
+        async fn insert(&self, title: String) -> Result<(), ServerFnError> {
+            self.db
+                .create::<Vec<Todo>>("todos")
+                .content(Todo {
+                    id: 0,
+                    title,
+                    completed: false,
+                })
+                .await
+                .map(|_rows| ())
+                .map_err(|e| ServerFnError::ServerError(e.to_string()))
+        }
+
+        async fn insert_with_attachment(
+            &self,
+            title: String,
+            attachment: Vec<u8>,
+        ) -> Result<(), ServerFnError> {
+            #[derive(serde::Serialize)]
+            struct NewTodo {
+                title: String,
+                completed: bool,
+                attachment: Vec<u8>,
+            }
+
+            self.db
+                .create::<Vec<Todo>>("todos")
+                .content(NewTodo {
+                    title,
+                    completed: false,
+                    attachment,
+                })
+                .await
+                .map(|_rows| ())
+                .map_err(|e| ServerFnError::ServerError(e.to_string()))
+        }
+
+        async fn insert_many(&self, items: Vec<Todo>) -> Result<(), ServerFnError> {
+            for todo in items {
+                self.db
+                    .create::<Vec<Todo>>("todos")
+                    .content(todo)
+                    .await
+                    .map_err(|e| ServerFnError::ServerError(e.to_string()))?;
+            }
+            Ok(())
+        }
+
+        async fn list(&self) -> Result<Vec<Todo>, ServerFnError> {
+            self.db
+                .select("todos")
+                .await
+                .map_err(|e| ServerFnError::ServerError(e.to_string()))
+        }
+
+        async fn toggle(&self, id: i64) -> Result<(), ServerFnError> {
+            self.db
+                .query("UPDATE todos SET completed = !completed WHERE id = $id")
+                .bind(("id", id))
+                .await
+                .map(|_response| ())
+                .map_err(|e| ServerFnError::ServerError(e.to_string()))
+        }
+
+        async fn delete(&self, id: i64) -> Result<(), ServerFnError> {
+            self.db
+                .query("DELETE todos WHERE id = $id")
+                .bind(("id", id))
+                .await
+                .map(|_response| ())
+                .map_err(|e| ServerFnError::ServerError(e.to_string()))
+        }
+    }
+}
@@ -1,19 +1,99 @@
 // todo.rs
 
 use leptos::*;
+use serde::{Deserialize, Serialize};
+
+/// A single todo item, shared between the client and server.
+#[derive(Clone, Debug, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)]
+#[archive(check_bytes)]
+pub struct Todo {
+    pub id: i64,
+    pub title: String,
+    pub completed: bool,
+}
+
+// Resolves the `TodoStore` provided in `main.rs`.
+#[cfg(feature = "ssr")]
+pub fn store(cx: Scope) -> Result<std::sync::Arc<dyn crate::store::TodoStore>, ServerFnError> {
+    use_context::<std::sync::Arc<dyn crate::store::TodoStore>>(cx)
+        .ok_or_else(|| ServerFnError::ServerError("TodoStore missing in context.".to_string()))
+}
 
 #[server(AddTodo, "/api")]
-pub async fn add_todo(title: String) -> Result<(), ServerFnError> {
+pub async fn add_todo(cx: Scope, title: String) -> Result<(), ServerFnError> {
     println!("add todo: {}", title);
-    let mut conn = db().await?;
-
-    match sqlx::query("INSERT INTO todos (title, completed) VALUES ($1, false)")
-        .bind(title)
-        .execute(&mut conn)
-        .await
-    {
-        Ok(_row) => Ok(()),
-        Err(e) => Err(ServerFnError::ServerError(e.to_string())),
+    store(cx)?.insert(title).await
+}
+
+#[server(ListTodos, "/api")]
+pub async fn list_todos(cx: Scope) -> Result<Vec<Todo>, ServerFnError> {
+    store(cx)?.list().await
+}
+
+// `multipart/form-data` input, read field-by-field below.
+#[server(AddTodoWithAttachment, "/api", input = MultipartFormData)]
+pub async fn add_todo_with_attachment(cx: Scope, data: MultipartData) -> Result<(), ServerFnError> {
+    let mut data = data.into_inner().ok_or_else(|| {
+        ServerFnError::ServerError("could not read multipart body".to_string())
+    })?;
+
+    let mut title = String::new();
+    let mut attachment = Vec::new();
+
+    while let Ok(Some(mut field)) = data.next_field().await {
+        match field.name().unwrap_or_default() {
+            "title" => {
+                while let Ok(Some(chunk)) = field.chunk().await {
+                    title.push_str(&String::from_utf8_lossy(&chunk));
+                }
+            }
+            "file" => {
+                while let Ok(Some(chunk)) = field.chunk().await {
+                    attachment.extend_from_slice(&chunk);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    println!("add todo with attachment: {} ({} bytes)", title, attachment.len());
+    store(cx)?.insert_with_attachment(title, attachment).await
+}
+
+// Archived input/output so the server validates the buffer in place.
+#[server(AddTodos, "/api", input = Rkyv, output = Rkyv)]
+pub async fn add_todos(cx: Scope, items: Vec<Todo>) -> Result<(), ServerFnError> {
+    println!("add {} todos", items.len());
+    store(cx)?.insert_many(items).await
+}
+
+/// Reads the todo list back through [`list_todos`], feeding a failed
+/// read into the `<ErrorBoundary>` in `app.rs`.
+#[component]
+pub fn TodoList(cx: Scope) -> impl IntoView {
+    let todos = create_resource(cx, || (), move |_| list_todos(cx));
+
+    view! {
+        cx,
+        <Suspense fallback=move || view! { cx, <p>"Loading todos..."</p> }>
+            {move || {
+                todos.read(cx).map(|result| {
+                    result
+                        .map_err(crate::error_template::AppError::from)
+                        .map(|todos| {
+                            view! { cx,
+                                <ul>
+                                    <For
+                                        each=move || todos.clone()
+                                        key=|todo| todo.id
+                                        view=move |cx, todo: Todo| view! { cx, <li>{todo.title}</li> }
+                                    />
+                                </ul>
+                            }
+                        })
+                })
+            }}
+        </Suspense>
     }
 }
 
@@ -31,3 +111,104 @@ pub fn BusyButton(cx: Scope) -> impl IntoView {
         </button>
     }
 }
+
+#[allow(unused_must_use)]
+#[component]
+pub fn AddTodosButton(cx: Scope) -> impl IntoView {
+    view! {
+        cx,
+        <button on:click=move |_| {
+            spawn_local(async move {
+                add_todos(cx, vec![
+                    Todo { id: 0, title: "Batch todo 1".to_string(), completed: false },
+                    Todo { id: 0, title: "Batch todo 2".to_string(), completed: false },
+                ]).await;
+            });
+        }>
+            "Add Todos (batch)"
+        </button>
+    }
+}
+
+/// Lets the user drag a file onto the drop zone (or pick one the usual
+/// way), pair it with a title, and post both to
+/// [`add_todo_with_attachment`] as `multipart/form-data`.
+#[component]
+pub fn FileUpload(cx: Scope) -> impl IntoView {
+    let (title, set_title) = create_signal(cx, String::new());
+    let file_input_ref = create_node_ref::<html::Input>(cx);
+
+    let on_drop = move |ev: web_sys::DragEvent| {
+        ev.prevent_default();
+        if let (Some(input), Some(data)) = (file_input_ref.get(), ev.data_transfer()) {
+            input.set_files(Some(&data.files().unwrap()));
+        }
+    };
+
+    let on_submit = move |ev: web_sys::SubmitEvent| {
+        ev.prevent_default();
+        let Some(input) = file_input_ref.get() else {
+            return;
+        };
+        let Some(files) = input.files() else {
+            return;
+        };
+        let Some(file) = files.get(0) else {
+            return;
+        };
+
+        let form_data = web_sys::FormData::new().unwrap();
+        form_data.append_with_str("title", &title.get()).unwrap();
+        form_data.append_with_blob("file", &file).unwrap();
+
+        spawn_local(async move {
+            let resp = gloo_net::http::Request::post("/api/add_todo_with_attachment")
+                .body(form_data)
+                .send()
+                .await;
+            if let Err(e) = resp {
+                leptos::error!("attachment upload failed: {e}");
+            }
+        });
+    };
+
+    view! {
+        cx,
+        <form on:submit=on_submit on:dragover=move |ev| ev.prevent_default() on:drop=on_drop>
+            <input
+                type="text"
+                placeholder="Title"
+                on:input=move |ev| set_title.set(event_target_value(&ev))
+            />
+            <input type="file" node_ref=file_input_ref/>
+            <button type="submit">"Upload"</button>
+        </form>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn todo_batch_round_trips_through_rkyv() {
+        let todos: Vec<Todo> = (0..300)
+            .map(|i| Todo {
+                id: i,
+                title: format!("todo {i}"),
+                completed: i % 2 == 0,
+            })
+            .collect();
+
+        let bytes = rkyv::to_bytes::<_, 8192>(&todos).expect("todos archive");
+        let archived =
+            rkyv::check_archived_root::<Vec<Todo>>(&bytes).expect("buffer validates in place");
+
+        assert_eq!(archived.len(), todos.len());
+        for (archived, todo) in archived.iter().zip(&todos) {
+            assert_eq!(archived.id, todo.id);
+            assert_eq!(archived.title.as_str(), todo.title);
+            assert_eq!(archived.completed, todo.completed);
+        }
+    }
+}